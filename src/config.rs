@@ -8,6 +8,12 @@ pub struct Config {
     pub cache: CacheConfig,
     pub social: SocialConfig,
     pub analytics: AnalyticsConfig,
+    #[serde(default)]
+    pub markdown: MarkdownConfig,
+    #[serde(default)]
+    pub otel: OtelConfig,
+    #[serde(default)]
+    pub reload: ReloadConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -23,6 +29,8 @@ pub struct SiteConfig {
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    #[serde(default)]
+    pub show_drafts: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -43,6 +51,54 @@ pub struct AnalyticsConfig {
     pub google_analytics_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarkdownConfig {
+    #[serde(default = "default_highlight_class_prefix")]
+    pub highlight_class_prefix: String,
+}
+
+fn default_highlight_class_prefix() -> String {
+    "hl-".to_string()
+}
+
+impl Default for MarkdownConfig {
+    fn default() -> Self {
+        Self {
+            highlight_class_prefix: default_highlight_class_prefix(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OtelConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_otlp_endpoint")]
+    pub otlp_endpoint: String,
+}
+
+fn default_otlp_endpoint() -> String {
+    "http://localhost:4317".to_string()
+}
+
+impl Default for OtelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            otlp_endpoint: default_otlp_endpoint(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ReloadConfig {
+    /// Watches `content/posts` and `templates` and hot-reloads them into the
+    /// running server. Off by default: production deployments should keep
+    /// the load-once-at-startup behavior.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
 impl Config {
     pub fn load() -> anyhow::Result<Self> {
         let config_path = Path::new("config.toml");
@@ -51,7 +107,21 @@ impl Config {
         }
 
         let config_str = std::fs::read_to_string(config_path)?;
-        let config: Config = toml::from_str(&config_str)?;
+        let mut config: Config = toml::from_str(&config_str)?;
+
+        if let Ok(show_drafts) = std::env::var("SHOW_DRAFTS") {
+            config.server.show_drafts = show_drafts == "1" || show_drafts.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(otlp_enabled) = std::env::var("OTLP_ENABLED") {
+            config.otel.enabled = otlp_enabled == "1" || otlp_enabled.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(reload_enabled) = std::env::var("RELOAD_ENABLED") {
+            config.reload.enabled =
+                reload_enabled == "1" || reload_enabled.eq_ignore_ascii_case("true");
+        }
+
         Ok(config)
     }
 
@@ -77,6 +147,7 @@ impl Default for Config {
             server: ServerConfig {
                 host: "0.0.0.0".to_string(),
                 port: 8080,
+                show_drafts: false,
             },
             cache: CacheConfig {
                 static_files_max_age: 31536000,
@@ -90,6 +161,9 @@ impl Default for Config {
             analytics: AnalyticsConfig {
                 google_analytics_id: None,
             },
+            markdown: MarkdownConfig::default(),
+            otel: OtelConfig::default(),
+            reload: ReloadConfig::default(),
         }
     }
 }
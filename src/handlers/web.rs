@@ -1,27 +1,200 @@
 use crate::{
     error::Result,
-    services::{BlogService, RssService, SitemapService},
+    services::{AtomService, BlogService, JsonFeedService, RssService, SitemapService},
+    utils::etag::etag_response,
     AppState,
 };
 use axum::{
     extract::{Path, State},
+    http::{HeaderMap, StatusCode, Uri},
     response::{IntoResponse, Response},
 };
 use std::collections::HashMap;
 
-#[tracing::instrument(name = "index", skip(state))]
-pub async fn serve_index(State(state): State<AppState>) -> Result<impl IntoResponse> {
+#[tracing::instrument(name = "index", skip(state, headers))]
+pub async fn serve_index(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
     tracing::info!("serving index");
 
-    let context = HashMap::new();
-    let html = state
-        .template_engine
-        .render_template("templates/index.html", &context)
-        .or_else(|_| {
-            state
-                .template_engine
-                .render_template("assets/index.html", &context)
-        })?;
+    let html = if let Some(cached) = state.page_cache.get("/") {
+        cached
+    } else {
+        let context = HashMap::new();
+        let rendered = state
+            .template_engine
+            .render_template("templates/index.html", &context)
+            .or_else(|_| {
+                state
+                    .template_engine
+                    .render_template("assets/index.html", &context)
+            })?;
+        state.page_cache.put("/", rendered.clone());
+        rendered
+    };
+
+    Ok(etag_response(
+        &headers,
+        "text/html; charset=utf-8",
+        &state.config.cache_control_html(),
+        true,
+        html,
+    ))
+}
+
+#[tracing::instrument(name = "blog", skip(state, headers))]
+pub async fn serve_blog(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
+    tracing::info!("serving blog page");
+
+    let content = state.content.snapshot();
+
+    let html = if let Some(cached) = state.page_cache.get("/blog") {
+        cached
+    } else {
+        let blog_content = state.template_engine.render_blog_list(&content.posts);
+        let mut context = HashMap::new();
+        context.insert("BLOG_POSTS".to_string(), blog_content);
+
+        let rendered = state
+            .template_engine
+            .render_template("templates/blog.html", &context)
+            .or_else(|_| {
+                state
+                    .template_engine
+                    .render_template("assets/blog.html", &context)
+            })?;
+        state.page_cache.put("/blog", rendered.clone());
+        rendered
+    };
+
+    Ok(etag_response(
+        &headers,
+        "text/html; charset=utf-8",
+        &state.config.cache_control_html(),
+        true,
+        html,
+    ))
+}
+
+#[tracing::instrument(name = "blog_post", fields(post_slug = %post_slug), skip(state, headers))]
+pub async fn serve_blog_post(
+    State(state): State<AppState>,
+    Path(post_slug): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response> {
+    tracing::info!("serving blog post: {}", post_slug);
+
+    let content = state.content.snapshot();
+    let post = match BlogService::get_post_by_slug(&content.posts, &post_slug) {
+        Some(post) => post,
+        None => {
+            // The slug may be an old alias for a renamed post; redirect to
+            // its canonical URL instead of 404ing straight away.
+            if let Some(slug) = content.alias_index.get(&post_slug) {
+                tracing::info!("redirecting post alias /blog/{} to /blog/{}", post_slug, slug);
+                return Ok(Response::builder()
+                    .status(StatusCode::MOVED_PERMANENTLY)
+                    .header("Location", format!("/blog/{}", slug))
+                    .body(String::new())
+                    .unwrap()
+                    .into_response());
+            }
+            return Err(crate::error::AppError::NotFound(format!(
+                "Post not found: {}",
+                post_slug
+            )));
+        }
+    };
+
+    tracing::debug!("found post: {}", post.title);
+    state.telemetry.record_post_view(&post.slug);
+
+    let cache_key = format!("/blog/{}", post.slug);
+    let html = if let Some(cached) = state.page_cache.get(&cache_key) {
+        cached
+    } else {
+        let mut context = HashMap::new();
+        context.insert("BLOG_TITLE".to_string(), post.title.clone());
+        context.insert("BLOG_DATE".to_string(), post.formatted_date());
+        context.insert("BLOG_SLUG".to_string(), post.slug.clone());
+
+        // Use description for meta tags instead of HTML excerpt
+        let meta_excerpt = if post.description.is_empty() {
+            post.title.clone()
+        } else {
+            post.description.clone()
+        };
+        context.insert("BLOG_EXCERPT".to_string(), meta_excerpt);
+
+        context.insert(
+            "BLOG_TAGS".to_string(),
+            state.template_engine.render_post_tags(&post.tags),
+        );
+        context.insert(
+            "BLOG_TAGS_PLAIN".to_string(),
+            state.template_engine.render_post_tags_plain(&post.tags),
+        );
+        context.insert("READING_TIME".to_string(), post.reading_time.to_string());
+        context.insert("BLOG_CONTENT".to_string(), post.content.clone());
+
+        let rendered = state
+            .template_engine
+            .render_template("templates/post.html", &context)
+            .or_else(|_| {
+                state
+                    .template_engine
+                    .render_template("assets/post.html", &context)
+            })?;
+        state.page_cache.put(&cache_key, rendered.clone());
+        rendered
+    };
+
+    Ok(etag_response(
+        &headers,
+        "text/html; charset=utf-8",
+        &state.config.cache_control_html(),
+        true,
+        html,
+    ))
+}
+
+#[tracing::instrument(name = "tags_index", skip(state))]
+pub async fn serve_tags_index(State(state): State<AppState>) -> Result<impl IntoResponse> {
+    tracing::info!("serving tags index");
+
+    let content = state.content.snapshot();
+    let html = if let Some(cached) = state.page_cache.get("/tags") {
+        cached
+    } else {
+        let tag_counts = crate::services::TagService::tag_counts(&content.tag_index);
+        let tag_cloud: String = tag_counts
+            .iter()
+            .map(|(slug, name, count)| {
+                format!(
+                    r#"<a href="/tags/{}" class="tag-cloud-item">{} <span class="tag-count">{}</span></a>"#,
+                    slug, name, count
+                )
+            })
+            .collect();
+
+        let mut context = HashMap::new();
+        context.insert("TAG_CLOUD".to_string(), tag_cloud);
+
+        let rendered = state
+            .template_engine
+            .render_template("templates/tags.html", &context)
+            .or_else(|_| {
+                state
+                    .template_engine
+                    .render_template("assets/tags.html", &context)
+            })?;
+        state.page_cache.put("/tags", rendered.clone());
+        rendered
+    };
 
     Ok(Response::builder()
         .header("Content-Type", "text/html; charset=utf-8")
@@ -31,22 +204,38 @@ pub async fn serve_index(State(state): State<AppState>) -> Result<impl IntoRespo
         .unwrap())
 }
 
-#[tracing::instrument(name = "blog", skip(state))]
-pub async fn serve_blog(State(state): State<AppState>) -> Result<impl IntoResponse> {
-    tracing::info!("serving blog page");
+#[tracing::instrument(name = "tag_page", fields(tag = %tag_slug), skip(state))]
+pub async fn serve_tag_page(
+    State(state): State<AppState>,
+    Path(tag_slug): Path<String>,
+) -> Result<impl IntoResponse> {
+    tracing::info!("serving tag page: {}", tag_slug);
 
-    let blog_content = state.template_engine.render_blog_list(&state.posts);
-    let mut context = HashMap::new();
-    context.insert("BLOG_POSTS".to_string(), blog_content);
+    let content = state.content.snapshot();
+    let entry = content.tag_index.get(&tag_slug).ok_or_else(|| {
+        crate::error::AppError::NotFound(format!("Tag not found: {}", tag_slug))
+    })?;
 
-    let html = state
-        .template_engine
-        .render_template("templates/blog.html", &context)
-        .or_else(|_| {
-            state
-                .template_engine
-                .render_template("assets/blog.html", &context)
-        })?;
+    let cache_key = format!("/tags/{}", tag_slug);
+    let html = if let Some(cached) = state.page_cache.get(&cache_key) {
+        cached
+    } else {
+        let blog_content = state.template_engine.render_blog_list(&entry.posts);
+        let mut context = HashMap::new();
+        context.insert("BLOG_POSTS".to_string(), blog_content);
+        context.insert("TAG_NAME".to_string(), entry.name.clone());
+
+        let rendered = state
+            .template_engine
+            .render_template("templates/blog.html", &context)
+            .or_else(|_| {
+                state
+                    .template_engine
+                    .render_template("assets/blog.html", &context)
+            })?;
+        state.page_cache.put(&cache_key, rendered.clone());
+        rendered
+    };
 
     Ok(Response::builder()
         .header("Content-Type", "text/html; charset=utf-8")
@@ -56,83 +245,132 @@ pub async fn serve_blog(State(state): State<AppState>) -> Result<impl IntoRespon
         .unwrap())
 }
 
-#[tracing::instrument(name = "blog_post", fields(post_slug = %post_slug), skip(state))]
-pub async fn serve_blog_post(
+#[tracing::instrument(name = "tag_rss_feed", fields(tag = %tag_slug), skip(state))]
+pub async fn serve_tag_rss_feed(
     State(state): State<AppState>,
-    Path(post_slug): Path<String>,
+    Path(tag_slug): Path<String>,
 ) -> Result<impl IntoResponse> {
-    tracing::info!("serving blog post: {}", post_slug);
+    tracing::info!("serving RSS feed for tag: {}", tag_slug);
 
-    let post = BlogService::get_post_by_slug(&state.posts, &post_slug).ok_or_else(|| {
-        crate::error::AppError::NotFound(format!("Post not found: {}", post_slug))
+    let content = state.content.snapshot();
+    let entry = content.tag_index.get(&tag_slug).ok_or_else(|| {
+        crate::error::AppError::NotFound(format!("Tag not found: {}", tag_slug))
     })?;
 
-    tracing::debug!("found post: {}", post.title);
+    let posts = BlogService::get_posts_by_tag(&content.posts, &tag_slug);
+    let tag = crate::services::TagFilter {
+        name: &entry.name,
+        slug: &tag_slug,
+    };
+    let rss_content = RssService::generate_feed(&state.config, &posts, Some(tag));
+
+    Ok(Response::builder()
+        .header("Content-Type", "application/rss+xml; charset=utf-8")
+        .header("Cache-Control", state.config.cache_control_html())
+        .body(rss_content)
+        .unwrap())
+}
+
+#[tracing::instrument(name = "tag_atom_feed", fields(tag = %tag_slug), skip(state))]
+pub async fn serve_tag_atom_feed(
+    State(state): State<AppState>,
+    Path(tag_slug): Path<String>,
+) -> Result<impl IntoResponse> {
+    tracing::info!("serving Atom feed for tag: {}", tag_slug);
 
-    let mut context = HashMap::new();
-    context.insert("BLOG_TITLE".to_string(), post.title.clone());
-    context.insert("BLOG_DATE".to_string(), post.formatted_date());
-    context.insert("BLOG_SLUG".to_string(), post_slug.clone());
+    let content = state.content.snapshot();
+    let entry = content.tag_index.get(&tag_slug).ok_or_else(|| {
+        crate::error::AppError::NotFound(format!("Tag not found: {}", tag_slug))
+    })?;
 
-    // Use description for meta tags instead of HTML excerpt
-    let meta_excerpt = if post.description.is_empty() {
-        post.title.clone()
-    } else {
-        post.description.clone()
+    let posts = BlogService::get_posts_by_tag(&content.posts, &tag_slug);
+    let tag = crate::services::TagFilter {
+        name: &entry.name,
+        slug: &tag_slug,
     };
-    context.insert("BLOG_EXCERPT".to_string(), meta_excerpt);
-
-    context.insert(
-        "BLOG_TAGS".to_string(),
-        state.template_engine.render_post_tags(&post.tags),
-    );
-    context.insert(
-        "BLOG_TAGS_PLAIN".to_string(),
-        state.template_engine.render_post_tags_plain(&post.tags),
-    );
-    context.insert("READING_TIME".to_string(), post.reading_time.to_string());
-    context.insert("BLOG_CONTENT".to_string(), post.content.clone());
-
-    let html = state
-        .template_engine
-        .render_template("templates/post.html", &context)
-        .or_else(|_| {
-            state
-                .template_engine
-                .render_template("assets/post.html", &context)
-        })?;
+    let atom_content = AtomService::generate_feed(&state.config, &posts, Some(tag));
 
     Ok(Response::builder()
-        .header("Content-Type", "text/html; charset=utf-8")
+        .header("Content-Type", "application/atom+xml; charset=utf-8")
         .header("Cache-Control", state.config.cache_control_html())
-        .header("X-Content-Type-Options", "nosniff")
-        .body(html)
+        .body(atom_content)
         .unwrap())
 }
 
-#[tracing::instrument(name = "rss_feed", skip(state))]
-pub async fn serve_rss_feed(State(state): State<AppState>) -> Result<impl IntoResponse> {
+#[tracing::instrument(name = "rss_feed", skip(state, headers))]
+pub async fn serve_rss_feed(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
     tracing::info!("serving RSS feed");
 
-    let rss_content = RssService::generate_feed(&state.config, &state.posts);
+    let content = state.content.snapshot();
+    let rss_content = RssService::generate_feed(&state.config, &content.posts, None);
+
+    Ok(etag_response(
+        &headers,
+        "application/rss+xml; charset=utf-8",
+        &state.config.cache_control_html(),
+        false,
+        rss_content,
+    ))
+}
+
+#[tracing::instrument(name = "json_feed", skip(state))]
+pub async fn serve_json_feed(State(state): State<AppState>) -> Result<impl IntoResponse> {
+    tracing::info!("serving JSON feed");
+
+    let content = state.content.snapshot();
+    let feed_content = JsonFeedService::generate_feed(&state.config, &content.posts);
 
     Ok(Response::builder()
-        .header("Content-Type", "application/rss+xml; charset=utf-8")
+        .header("Content-Type", "application/feed+json; charset=utf-8")
         .header("Cache-Control", state.config.cache_control_html())
-        .body(rss_content)
+        .body(feed_content)
         .unwrap())
 }
 
-#[tracing::instrument(name = "sitemap", skip(state))]
-pub async fn serve_sitemap(State(state): State<AppState>) -> Result<impl IntoResponse> {
-    tracing::info!("serving sitemap");
+#[tracing::instrument(name = "atom_feed", skip(state))]
+pub async fn serve_atom_feed(State(state): State<AppState>) -> Result<impl IntoResponse> {
+    tracing::info!("serving Atom feed");
 
-    let sitemap_content = SitemapService::generate_sitemap(&state.config, &state.posts);
+    let content = state.content.snapshot();
+    let atom_content = AtomService::generate_feed(&state.config, &content.posts, None);
 
     Ok(Response::builder()
-        .header("Content-Type", "application/xml; charset=utf-8")
+        .header("Content-Type", "application/atom+xml; charset=utf-8")
         .header("Cache-Control", state.config.cache_control_html())
-        .body(sitemap_content)
+        .body(atom_content)
+        .unwrap())
+}
+
+#[tracing::instrument(name = "sitemap", skip(state, headers))]
+pub async fn serve_sitemap(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
+    tracing::info!("serving sitemap");
+
+    let content = state.content.snapshot();
+    let sitemap_content =
+        SitemapService::generate_sitemap(&state.config, &content.posts, &content.tag_index);
+
+    Ok(etag_response(
+        &headers,
+        "application/xml; charset=utf-8",
+        &state.config.cache_control_html(),
+        false,
+        sitemap_content,
+    ))
+}
+
+#[tracing::instrument(name = "metrics", skip(state))]
+pub async fn serve_metrics(State(state): State<AppState>) -> Result<impl IntoResponse> {
+    tracing::info!("serving metrics");
+
+    Ok(Response::builder()
+        .header("Content-Type", "text/plain; version=0.0.4")
+        .body(state.telemetry.render_prometheus())
         .unwrap())
 }
 
@@ -202,6 +440,38 @@ pub async fn serve_manifest(State(state): State<AppState>) -> Result<impl IntoRe
         .unwrap())
 }
 
+/// Fallback for any unmatched route: redirects declared post aliases to
+/// their canonical `/blog/:slug` URL with a 301, otherwise renders 404.
+///
+/// `fallback` routes never run `track_page_hits` (axum's `route_layer`
+/// deliberately skips them, which is what keeps `MatchedPath` populated for
+/// every route that *is* matched), so this records the hit/request counters
+/// itself rather than relying on the middleware.
+#[tracing::instrument(name = "fallback", skip(state))]
+pub async fn serve_fallback(State(state): State<AppState>, uri: Uri) -> Result<Response> {
+    let path = uri.path().trim_start_matches('/');
+    state.telemetry.record_hit(uri.path());
+
+    let content = state.content.snapshot();
+    if let Some(slug) = content.alias_index.get(path) {
+        tracing::info!("redirecting alias /{} to /blog/{}", path, slug);
+        state
+            .telemetry
+            .record_request(uri.path(), StatusCode::MOVED_PERMANENTLY.as_u16());
+        return Ok(Response::builder()
+            .status(StatusCode::MOVED_PERMANENTLY)
+            .header("Location", format!("/blog/{}", slug))
+            .body(String::new())
+            .unwrap());
+    }
+
+    let response = serve_404(State(state.clone())).await?.into_response();
+    state
+        .telemetry
+        .record_request(uri.path(), response.status().as_u16());
+    Ok(response)
+}
+
 #[tracing::instrument(name = "not_found", skip(state))]
 pub async fn serve_404(State(state): State<AppState>) -> Result<impl IntoResponse> {
     tracing::warn!("serving 404 page");
@@ -1,63 +1,87 @@
-use axum::{routing::get, Router};
+use axum::{middleware, routing::get, Router};
+use std::time::Duration;
 use tower::ServiceBuilder;
 use tower_http::compression::{CompressionLayer, CompressionLevel};
 use tower_http::services::ServeDir;
 use tower_http::trace::TraceLayer;
-use tracing_subscriber::filter::{EnvFilter, LevelFilter};
 
 mod config;
 mod error;
 mod handlers;
 mod models;
 mod services;
+mod telemetry;
 mod utils;
+mod watcher;
 
 use config::Config;
-use models::BlogPost;
-use services::BlogService;
-use utils::TemplateEngine;
+use services::{BlogService, ContentSnapshot, ContentStore};
+use telemetry::Telemetry;
+use utils::{PageCache, TemplateEngine};
 
 #[derive(Clone)]
 pub struct AppState {
     pub config: Config,
-    pub posts: Vec<BlogPost>,
+    /// Posts plus their derived tag/alias indexes, swapped in as one unit by
+    /// the filesystem watcher when `[reload].enabled` is set.
+    pub content: ContentStore,
     pub template_engine: TemplateEngine,
+    pub telemetry: Telemetry,
+    pub page_cache: PageCache,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::builder()
-                .with_default_directive(LevelFilter::INFO.into())
-                .from_env_lossy(),
-        )
-        .init();
-    tracing::info!("tracing is initialized");
-
-    // Load configuration
+    // Load configuration. Tracing isn't initialized yet (it needs the config
+    // we're loading to know whether OTLP export is on), so a load failure is
+    // reported to stderr directly rather than silently falling back.
     let config = Config::load().unwrap_or_else(|e| {
-        tracing::warn!("Failed to load config: {}, using defaults", e);
+        eprintln!("Failed to load config: {}, using defaults", e);
         Config::default()
     });
 
+    // Initialize tracing, optionally exporting to an OTLP collector when
+    // `[otel]` is enabled.
+    let telemetry = telemetry::init(&config)?;
+    tracing::info!("tracing is initialized");
+
     // Load blog posts
-    let posts = BlogService::load_posts().unwrap_or_else(|e| {
+    let posts = BlogService::load_posts(
+        config.server.show_drafts,
+        &config.markdown.highlight_class_prefix,
+    )
+    .unwrap_or_else(|e| {
         tracing::error!("Failed to load blog posts: {}", e);
         Vec::new()
     });
 
     tracing::info!("Loaded {} blog posts", posts.len());
 
+    // Builds the tag/alias indexes alongside the posts they're derived from.
+    let snapshot = ContentSnapshot::build(posts);
+    tracing::info!("Indexed {} tags", snapshot.tag_index.len());
+    tracing::info!("Indexed {} post aliases", snapshot.alias_index.len());
+    let content = ContentStore::new(snapshot);
+
     // Initialize template engine
     let template_engine = TemplateEngine::new(config.clone());
 
+    // Rendered pages stay cached for the same window advertised in
+    // Cache-Control, so a hit never outlives what clients are told to trust.
+    let page_cache = PageCache::new(Duration::from_secs(config.cache.html_max_age));
+
+    // Watches content/posts and templates for changes and hot-reloads them
+    // in place; a no-op unless `[reload].enabled` is set, so production
+    // deployments keep the load-once-at-startup behavior.
+    watcher::spawn(&config, content.clone(), page_cache.clone());
+
     // Create application state
     let state = AppState {
         config: config.clone(),
-        posts,
+        content,
         template_engine,
+        telemetry,
+        page_cache,
     };
 
     // Build router
@@ -65,13 +89,24 @@ async fn main() -> anyhow::Result<()> {
         .route("/", get(handlers::serve_index))
         .route("/blog", get(handlers::serve_blog))
         .route("/blog/:post", get(handlers::serve_blog_post))
+        .route("/tags", get(handlers::serve_tags_index))
+        .route("/tags/:tag", get(handlers::serve_tag_page))
+        .route("/tags/:tag/rss.xml", get(handlers::serve_tag_rss_feed))
+        .route("/tags/:tag/atom.xml", get(handlers::serve_tag_atom_feed))
         .route("/feed.xml", get(handlers::serve_rss_feed))
+        .route("/feed.json", get(handlers::serve_json_feed))
+        .route("/atom.xml", get(handlers::serve_atom_feed))
         .route("/sitemap.xml", get(handlers::serve_sitemap))
+        .route("/metrics", get(handlers::serve_metrics))
         .route("/robots.txt", get(handlers::serve_robots_txt))
         .route("/manifest.json", get(handlers::serve_manifest))
-        .fallback(handlers::serve_404)
+        .fallback(handlers::serve_fallback)
         .nest_service("/assets", ServeDir::new("assets"))
         .nest_service("/static", ServeDir::new("static"))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            telemetry::track_page_hits,
+        ))
         .with_state(state)
         .layer(
             ServiceBuilder::new()
@@ -2,6 +2,25 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
+/// The deserialized shape of a post's leading `---`/`+++` fenced block.
+///
+/// Replaces the old per-field regex extraction with a single typed parse, so
+/// malformed frontmatter surfaces as one `BlogParsing` error instead of many.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FrontMatter {
+    pub title: String,
+    pub date: String,
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub updated: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BlogPost {
     pub title: String,
@@ -12,36 +31,36 @@ pub struct BlogPost {
     pub path: String,
     pub slug: String,
     pub tags: Vec<String>,
+    pub draft: bool,
+    pub updated: Option<String>,
+    pub aliases: Vec<String>,
     pub reading_time: u32,
     pub excerpt: String,
 }
 
 impl BlogPost {
-    pub fn new(
-        title: String,
-        date: String,
-        description: String,
-        content: String,
-        path: String,
-        tags: Vec<String>,
-    ) -> Self {
-        let date_parsed = chrono::DateTime::parse_from_str(&date, "%Y-%m-%d")
+    pub fn from_frontmatter(frontmatter: FrontMatter, content: String, path: String) -> Self {
+        let date_parsed = chrono::NaiveDate::parse_from_str(&frontmatter.date, "%Y-%m-%d")
             .ok()
-            .map(|dt| dt.with_timezone(&Utc));
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|dt| chrono::TimeZone::from_utc_datetime(&Utc, &dt));
 
-        let slug = Self::generate_slug(&title);
+        let slug = Self::generate_slug(&frontmatter.title);
         let reading_time = Self::calculate_reading_time(&content);
         let excerpt = Self::generate_excerpt(&content);
 
         Self {
-            title,
-            date,
+            title: frontmatter.title,
+            date: frontmatter.date,
             date_parsed,
-            description,
+            description: frontmatter.description,
             content,
             path,
             slug,
-            tags,
+            tags: frontmatter.tags,
+            draft: frontmatter.draft,
+            updated: frontmatter.updated,
+            aliases: frontmatter.aliases,
             reading_time,
             excerpt,
         }
@@ -125,6 +144,26 @@ impl BlogPost {
             self.date.clone()
         }
     }
+
+    /// A post is published once its `draft` flag is cleared and its date has
+    /// arrived. Posts with an unparseable date are treated as already
+    /// published rather than silently hidden.
+    ///
+    /// This is only re-evaluated when a post is (re)loaded, so a
+    /// future-dated post only goes live exactly on schedule when something
+    /// re-checks it around that time; with `[reload].enabled`, the watcher's
+    /// periodic publish-status recheck (see `watcher::PUBLISH_RECHECK_INTERVAL`)
+    /// does that. Without hot-reload, a restart is what picks it up.
+    pub fn is_published(&self) -> bool {
+        if self.draft {
+            return false;
+        }
+
+        match self.date_parsed {
+            Some(date) => date <= Utc::now(),
+            None => true,
+        }
+    }
 }
 
 impl PartialEq for BlogPost {
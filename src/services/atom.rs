@@ -0,0 +1,96 @@
+use crate::{config::Config, models::BlogPost, services::TagFilter};
+use atom_syndication::{
+    ContentBuilder, Entry, EntryBuilder, FeedBuilder, FixedDateTime, LinkBuilder, PersonBuilder,
+};
+use chrono::Utc;
+
+pub struct AtomService;
+
+impl AtomService {
+    pub fn generate_feed(config: &Config, posts: &[BlogPost], tag: Option<TagFilter>) -> String {
+        let author = PersonBuilder::default()
+            .name(config.site.author.clone())
+            .build();
+
+        let mut entries: Vec<Entry> = Vec::new();
+
+        for post in posts.iter().take(20) {
+            // Limit to most recent 20 posts, matching the RSS and JSON feeds
+            let url = format!("https://{}/blog/{}", config.site.domain, post.slug);
+
+            let link = LinkBuilder::default()
+                .href(url.clone())
+                .rel("alternate")
+                .build();
+
+            let content = ContentBuilder::default()
+                .content_type(Some("html".to_string()))
+                .value(Some(post.content.clone()))
+                .build();
+
+            let updated = Self::parse_date(post).unwrap_or_else(|| Utc::now().into());
+
+            let entry = EntryBuilder::default()
+                .title(post.title.clone())
+                .id(url)
+                .updated(updated)
+                .summary(Some(post.description.clone().into()))
+                .content(Some(content))
+                .links(vec![link])
+                .categories(
+                    post.tags
+                        .iter()
+                        .map(|tag| atom_syndication::CategoryBuilder::default().term(tag.clone()).build())
+                        .collect::<Vec<_>>(),
+                )
+                .build();
+
+            entries.push(entry);
+        }
+
+        let (title, id, home_href, feed_href) = match &tag {
+            Some(tag) => (
+                format!("{}: {}", config.site.title, tag.name),
+                format!("https://{}/tags/{}", config.site.domain, tag.slug),
+                format!("https://{}/tags/{}", config.site.domain, tag.slug),
+                format!(
+                    "https://{}/tags/{}/atom.xml",
+                    config.site.domain, tag.slug
+                ),
+            ),
+            None => (
+                config.site.title.clone(),
+                format!("https://{}/", config.site.domain),
+                format!("https://{}", config.site.domain),
+                format!("https://{}/atom.xml", config.site.domain),
+            ),
+        };
+
+        let feed_link = LinkBuilder::default().href(feed_href).rel("self").build();
+
+        let home_link = LinkBuilder::default()
+            .href(home_href)
+            .rel("alternate")
+            .build();
+
+        let updated = entries
+            .first()
+            .map(|entry| *entry.updated())
+            .unwrap_or_else(|| Utc::now().into());
+
+        let feed = FeedBuilder::default()
+            .title(title)
+            .id(id)
+            .updated(updated)
+            .authors(vec![author])
+            .links(vec![feed_link, home_link])
+            .entries(entries)
+            .build();
+
+        feed.to_string()
+    }
+
+    fn parse_date(post: &BlogPost) -> Option<FixedDateTime> {
+        post.date_parsed.map(|dt| dt.into())
+    }
+}
@@ -1,12 +1,32 @@
-use crate::{error::Result, models::BlogPost};
-use pulldown_cmark::{html, Parser};
-use regex::Regex;
+use crate::{
+    error::{AppError, Result},
+    models::{BlogPost, FrontMatter},
+};
+use pulldown_cmark::{html, CodeBlockKind, Event, Parser, Tag};
 use std::fs;
+use std::sync::OnceLock;
+use syntect::html::{ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+
+fn syntax_set() -> &'static SyntaxSet {
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
 
 pub struct BlogService;
 
 impl BlogService {
-    pub fn load_posts() -> Result<Vec<BlogPost>> {
+    /// Loads every markdown post under `content/posts` (or the legacy
+    /// `assets/posts`). Unpublished posts (drafts or future-dated) are
+    /// dropped unless `show_drafts` is set, so local previews can see
+    /// work-in-progress while deployed builds only ever see live posts.
+    ///
+    /// A post whose frontmatter fails to parse is logged and skipped rather
+    /// than failing the whole directory scan, so one malformed post can't
+    /// take every other post down with it.
+    pub fn load_posts(show_drafts: bool, highlight_class_prefix: &str) -> Result<Vec<BlogPost>> {
         let mut posts = Vec::new();
         let posts_dir = fs::read_dir("content/posts")
             .or_else(|_| fs::read_dir("assets/posts"))?; // Fallback to old location
@@ -14,7 +34,6 @@ impl BlogService {
         for entry in posts_dir {
             let entry = entry?;
             if entry.path().extension().and_then(|s| s.to_str()) == Some("md") {
-                let content = fs::read_to_string(entry.path())?;
                 let filename = entry
                     .path()
                     .file_stem()
@@ -22,8 +41,12 @@ impl BlogService {
                     .unwrap_or_default()
                     .to_string();
 
-                if let Some(post) = Self::parse_blog_post(&content, &filename)? {
-                    posts.push(post);
+                match Self::parse_post_file(&filename, show_drafts, highlight_class_prefix) {
+                    Ok(Some(post)) => posts.push(post),
+                    Ok(None) => {}
+                    Err(e) => {
+                        tracing::warn!("Skipping post {}: {}", filename, e);
+                    }
                 }
             }
         }
@@ -32,73 +55,229 @@ impl BlogService {
         Ok(posts)
     }
 
-    fn parse_blog_post(content: &str, filename: &str) -> Result<Option<BlogPost>> {
-        let parts: Vec<&str> = content.split("---").collect();
-        if parts.len() < 3 {
+    /// Reads and parses a single post by its file stem (the same identifier
+    /// stored in `BlogPost::path`), applying the same draft/future-dated
+    /// filtering as `load_posts`. Used by the hot-reload watcher to refresh
+    /// just the file that changed instead of re-reading the whole directory.
+    pub fn parse_post_file(
+        filename: &str,
+        show_drafts: bool,
+        highlight_class_prefix: &str,
+    ) -> Result<Option<BlogPost>> {
+        let file_path = format!("content/posts/{}.md", filename);
+        let content = fs::read_to_string(&file_path)
+            .or_else(|_| fs::read_to_string(format!("assets/posts/{}.md", filename)))?;
+
+        let post = Self::parse_blog_post(&content, filename, highlight_class_prefix)?;
+        Ok(post.filter(|post| show_drafts || post.is_published()))
+    }
+
+    fn parse_blog_post(
+        content: &str,
+        filename: &str,
+        highlight_class_prefix: &str,
+    ) -> Result<Option<BlogPost>> {
+        let Some((frontmatter_block, markdown, is_toml)) = Self::split_frontmatter(content) else {
             tracing::warn!("Invalid blog post format in file: {}", filename);
             return Ok(None);
-        }
+        };
 
-        // Parse frontmatter
-        let frontmatter = parts[1];
-        let title = Self::extract_frontmatter_field(frontmatter, "title")?;
-        let date = Self::extract_frontmatter_field(frontmatter, "date")?;
-        let description = Self::extract_frontmatter_field(frontmatter, "description")?;
-        
-        // Extract tags (optional)
-        let tags = Self::extract_frontmatter_list(frontmatter, "tags")
-            .unwrap_or_default();
-
-        // Parse markdown content
-        let markdown = parts[2..].join("---"); // Rejoin in case there are more --- in content
-        let parser = Parser::new(&markdown);
-        let mut html_output = String::new();
-        html::push_html(&mut html_output, parser);
+        let frontmatter: FrontMatter = if is_toml {
+            toml::from_str(frontmatter_block).map_err(|e| {
+                AppError::BlogParsing(format!("Invalid TOML frontmatter in {}: {}", filename, e))
+            })?
+        } else {
+            serde_yaml::from_str(frontmatter_block).map_err(|e| {
+                AppError::BlogParsing(format!("Invalid YAML frontmatter in {}: {}", filename, e))
+            })?
+        };
+
+        let html_output = Self::render_markdown(markdown, highlight_class_prefix);
 
         // Remove .md extension for the path
         let path = filename.strip_suffix(".md").unwrap_or(filename).to_string();
 
-        Ok(Some(BlogPost::new(
-            title,
-            date,
-            description,
-            html_output,
-            path,
-            tags,
-        )))
+        Ok(Some(BlogPost::from_frontmatter(frontmatter, html_output, path)))
     }
 
-    fn extract_frontmatter_field(frontmatter: &str, field: &str) -> Result<String> {
-        let pattern = format!(r"{}:\s*(.+)", field);
-        let re = Regex::new(&pattern).unwrap();
-        
-        re.captures(frontmatter)
-            .and_then(|caps| caps.get(1))
-            .map(|m| m.as_str().trim().trim_matches('"').to_string())
-            .ok_or_else(|| crate::error::AppError::BlogParsing(
-                format!("Missing {} field in frontmatter", field)
-            ))
+    /// Renders markdown to HTML, replacing fenced code blocks with
+    /// syntect-highlighted, class-based spans so themes live in CSS instead
+    /// of being baked into the post body.
+    fn render_markdown(markdown: &str, highlight_class_prefix: &str) -> String {
+        let parser = Parser::new(markdown);
+        let mut events = Vec::new();
+        let mut current_lang: Option<String> = None;
+        let mut code_buffer = String::new();
+
+        for event in parser {
+            match event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) => {
+                    current_lang = Some(lang.to_string());
+                    code_buffer.clear();
+                }
+                Event::Text(text) if current_lang.is_some() => {
+                    code_buffer.push_str(&text);
+                }
+                Event::End(Tag::CodeBlock(_)) if current_lang.is_some() => {
+                    let lang = current_lang.take().unwrap_or_default();
+                    let highlighted =
+                        Self::highlight_code_block(&code_buffer, &lang, highlight_class_prefix);
+                    events.push(Event::Html(highlighted.into()));
+                }
+                other => events.push(other),
+            }
+        }
+
+        let mut html_output = String::new();
+        html::push_html(&mut html_output, events.into_iter());
+        html_output
+    }
+
+    fn highlight_code_block(code: &str, lang: &str, class_prefix: &str) -> String {
+        // The info string can carry extra whitespace-separated modifiers
+        // (e.g. "rust ignore"); only the first token names the language,
+        // same as the pulldown_cmark HTML renderer this replaces.
+        let lang_token = lang.split_whitespace().next().unwrap_or_default();
+
+        let syntax_set = syntax_set();
+        let syntax = syntax_set
+            .find_syntax_by_token(lang_token)
+            .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+
+        let mut generator = ClassedHTMLGenerator::new_with_class_style(
+            syntax,
+            syntax_set,
+            ClassStyle::SpacedPrefixed {
+                prefix: Self::leaked_class_prefix(class_prefix),
+            },
+        );
+        for line in LinesWithEndings::from(code) {
+            let _ = generator.parse_html_for_line_which_includes_newline(line);
+        }
+
+        format!(
+            r#"<pre class="highlight"><code class="language-{}">{}</code></pre>"#,
+            Self::escape_html_attr(lang_token),
+            generator.finalize()
+        )
     }
 
-    fn extract_frontmatter_list(frontmatter: &str, field: &str) -> Result<Vec<String>> {
-        let pattern = format!(r"{}:\s*\[(.*?)\]", field);
-        let re = Regex::new(&pattern).unwrap();
-        
-        if let Some(caps) = re.captures(frontmatter) {
-            if let Some(list_content) = caps.get(1) {
-                let tags: Vec<String> = list_content
-                    .as_str()
-                    .split(',')
-                    .map(|tag| tag.trim().trim_matches('"').trim_matches('\'').to_string())
-                    .filter(|tag| !tag.is_empty())
-                    .collect();
-                return Ok(tags);
+    /// Minimal HTML-attribute escaping for untrusted text interpolated into
+    /// a `format!`-built tag, matching what `pulldown_cmark::html::push_html`
+    /// does for code-block info strings.
+    fn escape_html_attr(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for c in value.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#39;"),
+                _ => escaped.push(c),
             }
         }
-        Ok(Vec::new())
+        escaped
+    }
+
+    /// `ClassStyle::SpacedPrefixed` needs a `'static` prefix. The prefix
+    /// comes from config and is only ever read once at startup, so we leak
+    /// it into a cached `'static str` rather than re-deriving it per call.
+    fn leaked_class_prefix(prefix: &str) -> &'static str {
+        static PREFIX: OnceLock<&'static str> = OnceLock::new();
+        PREFIX.get_or_init(|| Box::leak(prefix.to_string().into_boxed_str()))
+    }
+
+    /// Splits off the leading fenced frontmatter block, supporting both the
+    /// `---` (YAML) and `+++` (TOML) delimiter conventions. Returns the
+    /// frontmatter text, the remaining markdown body, and whether the block
+    /// was TOML.
+    fn split_frontmatter(content: &str) -> Option<(&str, &str, bool)> {
+        let trimmed = content.trim_start();
+        if let Some(rest) = trimmed.strip_prefix("+++") {
+            let end = rest.find("+++")?;
+            Some((&rest[..end], &rest[end + 3..], true))
+        } else if let Some(rest) = trimmed.strip_prefix("---") {
+            let end = rest.find("---")?;
+            Some((&rest[..end], &rest[end + 3..], false))
+        } else {
+            None
+        }
     }
 
     pub fn get_post_by_slug<'a>(posts: &'a [BlogPost], slug: &str) -> Option<&'a BlogPost> {
         posts.iter().find(|post| post.slug == slug || post.path == slug)
     }
+
+    /// Filters posts carrying `tag_slug`, matching on the same slugified
+    /// form `TagService::build_index` keys its tag index by.
+    pub fn get_posts_by_tag(posts: &[BlogPost], tag_slug: &str) -> Vec<BlogPost> {
+        posts
+            .iter()
+            .filter(|post| {
+                post.tags
+                    .iter()
+                    .any(|tag| crate::services::TagService::slug(tag) == tag_slug)
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_yaml_frontmatter_with_updated_field() {
+        let content = "---\n\
+title: Hello World\n\
+date: 2024-01-15\n\
+description: A test post\n\
+updated: 2024-02-01\n\
+---\n\
+Body text.\n";
+
+        let post = BlogService::parse_blog_post(content, "hello-world", "highlight")
+            .expect("YAML frontmatter with `updated` should parse")
+            .expect("post should not be filtered out");
+
+        assert_eq!(post.title, "Hello World");
+        assert_eq!(post.updated.as_deref(), Some("2024-02-01"));
+    }
+
+    #[test]
+    fn parses_toml_frontmatter_with_updated_field() {
+        let content = "+++\n\
+title = \"Hello World\"\n\
+date = \"2024-01-15\"\n\
+description = \"A test post\"\n\
+updated = \"2024-02-01\"\n\
++++\n\
+Body text.\n";
+
+        let post = BlogService::parse_blog_post(content, "hello-world", "highlight")
+            .expect("TOML frontmatter with `updated` should parse")
+            .expect("post should not be filtered out");
+
+        assert_eq!(post.updated.as_deref(), Some("2024-02-01"));
+    }
+
+    #[test]
+    fn highlight_code_block_escapes_the_info_string() {
+        let highlighted = BlogService::highlight_code_block(
+            "fn main() {}",
+            "\"><script>alert(1)</script>",
+            "highlight",
+        );
+
+        assert!(!highlighted.contains("<script>"));
+        assert!(highlighted.contains("language-&quot;&gt;&lt;script&gt;alert(1)&lt;/script&gt;"));
+    }
+
+    #[test]
+    fn highlight_code_block_uses_only_the_first_info_string_token() {
+        let highlighted = BlogService::highlight_code_block("fn main() {}", "rust ignore", "highlight");
+        assert!(highlighted.contains(r#"language-rust"#));
+    }
 }
@@ -0,0 +1,143 @@
+use crate::models::BlogPost;
+use crate::services::{BlogService, TagEntry, TagService};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Everything derived from the posts on disk: the posts themselves plus the
+/// tag and alias indexes built from them. Rebuilt as one unit so a reload
+/// never leaves the indexes pointing at stale posts.
+#[derive(Clone)]
+pub struct ContentSnapshot {
+    pub posts: Vec<BlogPost>,
+    pub tag_index: HashMap<String, TagEntry>,
+    pub alias_index: HashMap<String, String>,
+}
+
+impl ContentSnapshot {
+    pub fn build(posts: Vec<BlogPost>) -> Self {
+        let tag_index = TagService::build_index(&posts);
+        let alias_index = Self::build_alias_index(&posts);
+        Self {
+            posts,
+            tag_index,
+            alias_index,
+        }
+    }
+
+    fn build_alias_index(posts: &[BlogPost]) -> HashMap<String, String> {
+        let mut index = HashMap::new();
+        for post in posts {
+            for alias in &post.aliases {
+                index.insert(Self::normalize_alias(alias), post.slug.clone());
+            }
+        }
+        index
+    }
+
+    /// Aliases are looked up by bare slug (`serve_blog_post` strips `/blog/`
+    /// before checking the index, and `serve_fallback` sees paths with no
+    /// leading slash), but authors naturally write aliases as the full old
+    /// URL, e.g. `/blog/old-slug`. Strip both so either form resolves to the
+    /// same key.
+    fn normalize_alias(alias: &str) -> String {
+        alias
+            .trim_start_matches('/')
+            .trim_start_matches("blog/")
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(slug: &str, aliases: Vec<&str>) -> BlogPost {
+        BlogPost {
+            title: slug.to_string(),
+            date: "2024-01-01".to_string(),
+            date_parsed: None,
+            description: String::new(),
+            content: String::new(),
+            path: format!("content/posts/{}.md", slug),
+            slug: slug.to_string(),
+            tags: Vec::new(),
+            draft: false,
+            updated: None,
+            aliases: aliases.into_iter().map(String::from).collect(),
+            reading_time: 1,
+            excerpt: String::new(),
+        }
+    }
+
+    #[test]
+    fn alias_index_resolves_to_the_canonical_slug() {
+        let snapshot = ContentSnapshot::build(vec![post("new-slug", vec!["/old-slug"])]);
+
+        assert_eq!(
+            snapshot.alias_index.get("old-slug").map(String::as_str),
+            Some("new-slug")
+        );
+    }
+
+    #[test]
+    fn alias_index_strips_a_leading_blog_segment_too() {
+        let snapshot = ContentSnapshot::build(vec![post("new-slug", vec!["/blog/old-slug"])]);
+
+        assert_eq!(
+            snapshot.alias_index.get("old-slug").map(String::as_str),
+            Some("new-slug")
+        );
+    }
+
+    #[test]
+    fn alias_index_has_no_entry_for_an_undeclared_alias() {
+        let snapshot = ContentSnapshot::build(vec![post("new-slug", vec!["/old-slug"])]);
+
+        assert!(snapshot.alias_index.get("new-slug").is_none());
+        assert!(snapshot.alias_index.get("unrelated").is_none());
+    }
+}
+
+/// Holds the current `ContentSnapshot` behind a lock so the filesystem
+/// watcher can swap in a freshly rebuilt snapshot while handlers keep
+/// serving the previous one mid-request. Cheap to clone: handlers get their
+/// own `Arc` of whichever snapshot was current when they asked.
+#[derive(Clone)]
+pub struct ContentStore {
+    current: Arc<RwLock<Arc<ContentSnapshot>>>,
+}
+
+impl ContentStore {
+    pub fn new(snapshot: ContentSnapshot) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(snapshot))),
+        }
+    }
+
+    pub fn snapshot(&self) -> Arc<ContentSnapshot> {
+        self.current.read().unwrap().clone()
+    }
+
+    pub fn replace(&self, snapshot: ContentSnapshot) {
+        *self.current.write().unwrap() = Arc::new(snapshot);
+    }
+
+    /// Re-parses a single changed post file and swaps it into a fresh
+    /// snapshot, rebuilding the derived tag/alias indexes so they stay in
+    /// sync. If the file no longer yields a post (e.g. it was just deleted,
+    /// fails to parse, or is now unpublished), that post is simply dropped
+    /// from the snapshot; no other post is re-read.
+    pub fn reload_post(&self, path: &str, show_drafts: bool, highlight_class_prefix: &str) {
+        let mut posts = self.snapshot().posts.clone();
+        posts.retain(|post| post.path != path);
+
+        if let Ok(Some(post)) =
+            BlogService::parse_post_file(path, show_drafts, highlight_class_prefix)
+        {
+            posts.push(post);
+        }
+
+        posts.sort();
+        self.replace(ContentSnapshot::build(posts));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{config::Config, models::BlogPost};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize)]
+struct JsonFeedItem {
+    id: String,
+    url: String,
+    title: String,
+    content_html: String,
+    summary: String,
+    date_published: String,
+    tags: Vec<String>,
+}
+
+pub struct JsonFeedService;
+
+impl JsonFeedService {
+    pub fn generate_feed(config: &Config, posts: &[BlogPost]) -> String {
+        let items: Vec<JsonFeedItem> = posts
+            .iter()
+            .take(20) // Limit to most recent 20 posts, matching the RSS feed
+            .map(|post| {
+                let url = format!("https://{}/blog/{}", config.site.domain, post.slug);
+                JsonFeedItem {
+                    id: url.clone(),
+                    url,
+                    title: post.title.clone(),
+                    content_html: post.content.clone(),
+                    summary: post.description.clone(),
+                    date_published: post.iso_date(),
+                    tags: post.tags.clone(),
+                }
+            })
+            .collect();
+
+        let feed = json!({
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": config.site.title,
+            "home_page_url": format!("https://{}", config.site.domain),
+            "feed_url": format!("https://{}/feed.json", config.site.domain),
+            "description": config.site.description,
+            "items": items,
+        });
+
+        serde_json::to_string_pretty(&feed).unwrap_or_default()
+    }
+}
@@ -1,7 +1,22 @@
+pub mod atom;
 pub mod blog;
+pub mod content_store;
+pub mod json_feed;
 pub mod rss;
 pub mod sitemap;
+pub mod tags;
 
+pub use atom::AtomService;
 pub use blog::BlogService;
+pub use content_store::{ContentSnapshot, ContentStore};
+pub use json_feed::JsonFeedService;
 pub use rss::RssService;
 pub use sitemap::SitemapService;
+pub use tags::{TagEntry, TagService};
+
+/// Narrows a feed to a single tag's posts; the feed services use the name
+/// for the channel title and the slug for its `/tags/{slug}` links.
+pub struct TagFilter<'a> {
+    pub name: &'a str,
+    pub slug: &'a str,
+}
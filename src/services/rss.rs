@@ -1,10 +1,10 @@
-use crate::{config::Config, models::BlogPost};
+use crate::{config::Config, models::BlogPost, services::TagFilter};
 use rss::{Category, ChannelBuilder, GuidBuilder, ItemBuilder};
 
 pub struct RssService;
 
 impl RssService {
-    pub fn generate_feed(config: &Config, posts: &[BlogPost]) -> String {
+    pub fn generate_feed(config: &Config, posts: &[BlogPost], tag: Option<TagFilter>) -> String {
         let mut items = Vec::new();
 
         for post in posts.iter().take(20) {
@@ -41,9 +41,20 @@ impl RssService {
             items.push(item);
         }
 
+        let (title, link) = match &tag {
+            Some(tag) => (
+                format!("{}: {}", config.site.title, tag.name),
+                format!("https://{}/tags/{}", config.site.domain, tag.slug),
+            ),
+            None => (
+                config.site.title.clone(),
+                format!("https://{}", config.site.domain),
+            ),
+        };
+
         let channel = ChannelBuilder::default()
-            .title(&config.site.title)
-            .link(format!("https://{}", config.site.domain))
+            .title(title)
+            .link(link)
             .description(&config.site.description)
             .language(Some(config.site.language.clone()))
             .managing_editor(Some(format!(
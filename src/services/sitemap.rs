@@ -1,9 +1,14 @@
-use crate::{config::Config, models::BlogPost};
+use crate::{config::Config, models::BlogPost, services::TagEntry};
+use std::collections::HashMap;
 
 pub struct SitemapService;
 
 impl SitemapService {
-    pub fn generate_sitemap(config: &Config, posts: &[BlogPost]) -> String {
+    pub fn generate_sitemap(
+        config: &Config,
+        posts: &[BlogPost],
+        tag_index: &HashMap<String, TagEntry>,
+    ) -> String {
         let mut sitemap = String::from(
             r#"<?xml version="1.0" encoding="UTF-8"?>
 <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"
@@ -75,6 +80,31 @@ impl SitemapService {
             ));
         }
 
+        // Add tag index and per-tag listing pages
+        sitemap.push_str(&format!(
+            r#"  <url>
+    <loc>https://{}/tags</loc>
+    <changefreq>weekly</changefreq>
+    <priority>0.5</priority>
+  </url>
+"#,
+            config.site.domain
+        ));
+
+        let mut tag_slugs: Vec<&String> = tag_index.keys().collect();
+        tag_slugs.sort();
+        for slug in tag_slugs {
+            sitemap.push_str(&format!(
+                r#"  <url>
+    <loc>https://{}/tags/{}</loc>
+    <changefreq>weekly</changefreq>
+    <priority>0.4</priority>
+  </url>
+"#,
+                config.site.domain, slug
+            ));
+        }
+
         sitemap.push_str("</urlset>");
         sitemap
     }
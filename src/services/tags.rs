@@ -0,0 +1,56 @@
+use crate::models::BlogPost;
+use std::collections::HashMap;
+
+/// A single tag's display name plus the posts carrying it, keyed in
+/// `TagService::build_index` by the tag's URL slug.
+#[derive(Clone, Debug)]
+pub struct TagEntry {
+    pub name: String,
+    pub posts: Vec<BlogPost>,
+}
+
+pub struct TagService;
+
+impl TagService {
+    /// Groups posts by tag slug so `/tags/:tag` can look a tag up directly
+    /// from the route parameter without re-deriving the slug from every
+    /// post on each request.
+    pub fn build_index(posts: &[BlogPost]) -> HashMap<String, TagEntry> {
+        let mut index: HashMap<String, TagEntry> = HashMap::new();
+
+        for post in posts {
+            for tag in &post.tags {
+                let slug = Self::slug(tag);
+                let entry = index.entry(slug).or_insert_with(|| TagEntry {
+                    name: tag.clone(),
+                    posts: Vec::new(),
+                });
+                entry.posts.push(post.clone());
+            }
+        }
+
+        index
+    }
+
+    pub fn slug(tag: &str) -> String {
+        tag.to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// Tag name and slug paired with its post count, sorted alphabetically
+    /// for a stable tag cloud.
+    pub fn tag_counts(index: &HashMap<String, TagEntry>) -> Vec<(String, String, usize)> {
+        let mut counts: Vec<(String, String, usize)> = index
+            .iter()
+            .map(|(slug, entry)| (slug.clone(), entry.name.clone(), entry.posts.len()))
+            .collect();
+        counts.sort_by(|a, b| a.1.to_lowercase().cmp(&b.1.to_lowercase()));
+        counts
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{config::Config, AppState};
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use opentelemetry::metrics::Counter;
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use prometheus::{Encoder, Registry, TextEncoder};
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Handles to the metrics pipeline. `page_hit_count` is `None` when
+/// `[otel]` is disabled, so OTLP export stays a no-op in the default,
+/// zero-external-dependency build. `http_requests_total` and
+/// `blog_post_views_total` are always registered and are scraped locally
+/// from `/metrics`, independent of whether OTLP export is on.
+#[derive(Clone)]
+pub struct Telemetry {
+    page_hit_count: Option<Counter<u64>>,
+    http_requests_total: Counter<u64>,
+    blog_post_views_total: Counter<u64>,
+    prometheus_registry: Registry,
+}
+
+impl Telemetry {
+    pub fn record_hit(&self, path: &str) {
+        if let Some(counter) = &self.page_hit_count {
+            counter.add(1, &[KeyValue::new("path", path.to_string())]);
+        }
+    }
+
+    pub fn record_request(&self, route: &str, status: u16) {
+        self.http_requests_total.add(
+            1,
+            &[
+                KeyValue::new("route", route.to_string()),
+                KeyValue::new("status", status.to_string()),
+            ],
+        );
+    }
+
+    pub fn record_post_view(&self, slug: &str) {
+        self.blog_post_views_total
+            .add(1, &[KeyValue::new("slug", slug.to_string())]);
+    }
+
+    /// Renders every registered counter in Prometheus text exposition
+    /// format, for the `/metrics` endpoint to return as-is.
+    pub fn render_prometheus(&self) -> String {
+        let metric_families = self.prometheus_registry.gather();
+        let mut buffer = Vec::new();
+        let encoder = TextEncoder::new();
+        if let Err(e) = encoder.encode(&metric_families, &mut buffer) {
+            tracing::error!("failed to encode Prometheus metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+/// Sets up tracing, layering OTLP span export on top of the usual `fmt`
+/// output when `[otel]` is enabled (via config or the `OTLP_ENABLED` env
+/// var), and registers the request/page-view counters a Prometheus scraper
+/// can read from `/metrics` regardless of whether OTLP export is on.
+pub fn init(config: &Config) -> anyhow::Result<Telemetry> {
+    let env_filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .from_env_lossy();
+    let fmt_layer = tracing_subscriber::fmt::layer();
+
+    let page_hit_count = if config.otel.enabled {
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(&config.otel.otlp_endpoint),
+            )
+            .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+
+        tracing_subscriber::registry()
+            .with(env_filter)
+            .with(fmt_layer)
+            .with(tracing_opentelemetry::layer().with_tracer(tracer))
+            .init();
+
+        let otlp_meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(opentelemetry_sdk::runtime::Tokio)
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(&config.otel.otlp_endpoint),
+            )
+            .build()?;
+        global::set_meter_provider(otlp_meter_provider);
+
+        let meter = global::meter("jmrl");
+        Some(
+            meter
+                .u64_counter("page_hit_count")
+                .with_description("Number of requests served, by path")
+                .init(),
+        )
+    } else {
+        tracing_subscriber::registry()
+            .with(env_filter)
+            .with(fmt_layer)
+            .init();
+        None
+    };
+
+    // The Prometheus registry backs `/metrics` and is independent of the
+    // OTLP pipeline above, so request counters stay visible even when no
+    // collector is configured.
+    let prometheus_registry = Registry::new();
+    let exporter = opentelemetry_prometheus::exporter()
+        .with_registry(prometheus_registry.clone())
+        .build()?;
+    let prometheus_meter_provider = SdkMeterProvider::builder().with_reader(exporter).build();
+    let prometheus_meter = prometheus_meter_provider.meter("jmrl");
+
+    let http_requests_total = prometheus_meter
+        .u64_counter("http_requests_total")
+        .with_description("Total HTTP requests, labeled by route and status")
+        .init();
+    let blog_post_views_total = prometheus_meter
+        .u64_counter("blog_post_views_total")
+        .with_description("Total blog post views, labeled by slug")
+        .init();
+
+    Ok(Telemetry {
+        page_hit_count,
+        http_requests_total,
+        blog_post_views_total,
+        prometheus_registry,
+    })
+}
+
+/// Middleware that records a page hit (tagged with the request path) and
+/// the request/status counter (tagged with the matched route) for every
+/// request, independent of which handler ends up serving it.
+pub async fn track_page_hits(
+    State(state): State<AppState>,
+    matched_path: Option<MatchedPath>,
+    req: Request,
+    next: Next,
+) -> Response {
+    state.telemetry.record_hit(req.uri().path());
+    let route = matched_path
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+
+    let response = next.run(req).await;
+    state
+        .telemetry
+        .record_request(&route, response.status().as_u16());
+    response
+}
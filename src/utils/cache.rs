@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+struct CachedPage {
+    body: String,
+    rendered_at: Instant,
+}
+
+/// Caches fully-rendered HTML bodies keyed by route (e.g. `/blog` or
+/// `/blog/my-post`), so repeat requests skip the disk read and template
+/// substitution that `TemplateEngine::render_template` would otherwise redo
+/// every time. Entries are served as-is while younger than `ttl`, which is
+/// sized from the same `Config::cache.html_max_age` the `Cache-Control`
+/// header already advertises to clients.
+#[derive(Clone)]
+pub struct PageCache {
+    entries: Arc<RwLock<HashMap<String, CachedPage>>>,
+    ttl: Duration,
+}
+
+impl PageCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(HashMap::new())),
+            ttl,
+        }
+    }
+
+    /// Returns the cached body for `key` if it's still fresh.
+    pub fn get(&self, key: &str) -> Option<String> {
+        let entries = self.entries.read().unwrap();
+        entries.get(key).and_then(|cached| {
+            if cached.rendered_at.elapsed() < self.ttl {
+                Some(cached.body.clone())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn put(&self, key: &str, body: String) {
+        let mut entries = self.entries.write().unwrap();
+        entries.insert(
+            key.to_string(),
+            CachedPage {
+                body,
+                rendered_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Drops every cached entry, so the next request rebuilds its page. The
+    /// hot-reload watcher calls this when a post or template changes.
+    pub fn invalidate(&self) {
+        self.entries.write().unwrap().clear();
+    }
+}
@@ -0,0 +1,91 @@
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+/// Builds a response with a strong `ETag` derived from the rendered body,
+/// honoring the request's `If-None-Match` with a bodyless `304` instead of
+/// re-sending pages most clients already have. Used by the index, blog
+/// list, blog post, RSS feed and sitemap handlers; the tag pages, tag feeds
+/// and the JSON and Atom feeds send no `ETag`.
+pub fn etag_response(
+    headers: &HeaderMap,
+    content_type: &str,
+    cache_control: &str,
+    nosniff: bool,
+    body: String,
+) -> Response {
+    let etag = format!("\"{:x}\"", seahash::hash(body.as_bytes()));
+
+    let if_none_match = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok());
+
+    if if_none_match == Some(etag.as_str()) {
+        let mut response = Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header(header::ETAG, &etag)
+            .header(header::CACHE_CONTROL, cache_control);
+        if nosniff {
+            response = response.header(header::X_CONTENT_TYPE_OPTIONS, "nosniff");
+        }
+        return response.body(String::new()).unwrap().into_response();
+    }
+
+    let mut response = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::CACHE_CONTROL, cache_control)
+        .header(header::ETAG, etag);
+    if nosniff {
+        response = response.header(header::X_CONTENT_TYPE_OPTIONS, "nosniff");
+    }
+    response.body(body).unwrap().into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_304_when_if_none_match_matches_the_etag() {
+        let body = "hello world".to_string();
+        let etag = format!("\"{:x}\"", seahash::hash(body.as_bytes()));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag.parse().unwrap());
+
+        let response = etag_response(&headers, "text/html", "public, max-age=60", true, body);
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers().get(header::ETAG).unwrap(), &etag);
+    }
+
+    #[test]
+    fn returns_200_when_if_none_match_is_stale() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, "\"stale\"".parse().unwrap());
+
+        let response = etag_response(
+            &headers,
+            "text/html",
+            "public, max-age=60",
+            false,
+            "hello world".to_string(),
+        );
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::ETAG).is_some());
+    }
+
+    #[test]
+    fn returns_200_when_if_none_match_header_is_absent() {
+        let response = etag_response(
+            &HeaderMap::new(),
+            "text/html",
+            "public, max-age=60",
+            false,
+            "hello world".to_string(),
+        );
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
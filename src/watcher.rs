@@ -0,0 +1,109 @@
+use crate::config::Config;
+use crate::services::{BlogService, ContentSnapshot, ContentStore};
+use crate::utils::PageCache;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// How often the watcher re-scans every post's publish status when it isn't
+/// otherwise woken by a filesystem event. A future-dated post has no file
+/// event to trigger on the day it's scheduled to go live, so this is what
+/// actually makes scheduled publishing work rather than just "on next edit".
+const PUBLISH_RECHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Watches `content/posts` and `templates` for changes and hot-reloads the
+/// affected content in place, so editing a post or template no longer
+/// requires a restart. A no-op unless `[reload].enabled` is set.
+///
+/// Also re-scans every post's publish status on `PUBLISH_RECHECK_INTERVAL`,
+/// since a future-dated post going live has no filesystem event to wake the
+/// watcher on its publish date.
+pub fn spawn(config: &Config, content: ContentStore, page_cache: PageCache) {
+    if !config.reload.enabled {
+        return;
+    }
+
+    let show_drafts = config.server.show_drafts;
+    let highlight_class_prefix = config.markdown.highlight_class_prefix.clone();
+
+    std::thread::spawn(move || {
+        let (tx, rx) = channel::<notify::Result<Event>>();
+        let mut watcher = match RecommendedWatcher::new(tx, notify::Config::default()) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                tracing::error!("failed to start filesystem watcher: {}", e);
+                return;
+            }
+        };
+
+        for dir in ["content/posts", "templates"] {
+            if !Path::new(dir).exists() {
+                continue;
+            }
+            if let Err(e) = watcher.watch(Path::new(dir), RecursiveMode::Recursive) {
+                tracing::warn!("failed to watch {}: {}", dir, e);
+            }
+        }
+
+        tracing::info!("hot-reload watcher is active");
+
+        // Tracked against a fixed deadline rather than re-armed on every
+        // `recv_timeout` call, so frequent filesystem events (editor
+        // autosave, a script touching mtimes) can't keep resetting the full
+        // interval and starve the recheck indefinitely.
+        let mut next_recheck = Instant::now() + PUBLISH_RECHECK_INTERVAL;
+
+        loop {
+            let timeout = next_recheck.saturating_duration_since(Instant::now());
+            match rx.recv_timeout(timeout) {
+                Ok(Ok(event)) => {
+                    if !matches!(
+                        event.kind,
+                        EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)
+                    ) {
+                        continue;
+                    }
+
+                    for path in &event.paths {
+                        if path.extension().and_then(|s| s.to_str()) == Some("md") {
+                            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                                continue;
+                            };
+                            tracing::info!("post changed, reloading: {}", stem);
+                            content.reload_post(stem, show_drafts, &highlight_class_prefix);
+                            page_cache.invalidate();
+                        } else if path.components().any(|c| c.as_os_str() == "templates") {
+                            tracing::info!(
+                                "template changed, invalidating page cache: {}",
+                                path.display()
+                            );
+                            page_cache.invalidate();
+                        }
+                    }
+                }
+                Ok(Err(_)) => continue,
+                Err(RecvTimeoutError::Timeout) => {
+                    next_recheck = Instant::now() + PUBLISH_RECHECK_INTERVAL;
+                    match BlogService::load_posts(show_drafts, &highlight_class_prefix) {
+                        Ok(posts) => {
+                            let current_slugs: Vec<&str> =
+                                content.snapshot().posts.iter().map(|p| p.slug.as_str()).collect();
+                            let reloaded_slugs: Vec<&str> =
+                                posts.iter().map(|p| p.slug.as_str()).collect();
+                            if current_slugs != reloaded_slugs {
+                                tracing::info!(
+                                    "publish status changed on recheck, reloading all posts"
+                                );
+                                content.replace(ContentSnapshot::build(posts));
+                                page_cache.invalidate();
+                            }
+                        }
+                        Err(e) => tracing::warn!("publish-status recheck failed: {}", e),
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    });
+}